@@ -2,8 +2,10 @@ use crate::context::LintContext;
 use crate::rustc_middle::ty::TypeFoldable;
 use crate::LateContext;
 use crate::LateLintPass;
+use rustc_errors::Applicability;
 use rustc_hir::def::DefKind;
 use rustc_hir::{Expr, ExprKind};
+use rustc_infer::traits::util::elaborate_predicates;
 use rustc_middle::ty;
 use rustc_span::symbol::sym;
 
@@ -29,6 +31,10 @@ declare_lint! {
     /// that end up not doing anything. For instance, `Clone` is implemented on all `&T`, but
     /// calling `clone` on a `&T` where `T` does not implement clone, actually doesn't do anything
     /// as references are copy. This lint detects these calls and warns the user about them.
+    /// The same applies to `Deref`, `Borrow` and `ToOwned`, which are also implemented for
+    /// references and can likewise produce calls that do nothing. `to_owned` is also flagged when
+    /// called on a value of a `Clone` type that is not a reference, as it returns a clone of the
+    /// same type.
     pub NOOP_METHOD_CALL,
     Warn,
     "detects the use of well-known noop methods"
@@ -43,14 +49,16 @@ impl<'tcx> LateLintPass<'tcx> for NoopMethodCall {
             ExprKind::MethodCall(call, _, elements, _) => (call, elements),
             _ => return,
         };
-        // We only care about method calls corresponding to the `Clone`, `Deref` and `Borrow`
-        // traits and ignore any other method call.
+        // We only care about method calls corresponding to the `Clone`, `Deref`, `Borrow` and
+        // `ToOwned` traits and ignore any other method call.
         let (trait_id, did) = match cx.typeck_results().type_dependent_def(expr.hir_id) {
             // Verify we are dealing with a method/associated function.
             Some((DefKind::AssocFn, did)) => match cx.tcx.trait_of_item(did) {
                 // Check that we're dealing with a trait method for one of the traits we care about.
                 Some(trait_id)
-                    if [sym::Clone].iter().any(|s| cx.tcx.is_diagnostic_item(*s, trait_id)) =>
+                    if [sym::Clone, sym::Deref, sym::Borrow, sym::ToOwned]
+                        .iter()
+                        .any(|s| cx.tcx.is_diagnostic_item(*s, trait_id)) =>
                 {
                     (trait_id, did)
                 }
@@ -60,8 +68,15 @@ impl<'tcx> LateLintPass<'tcx> for NoopMethodCall {
         };
         let substs = cx.typeck_results().node_substs(expr.hir_id);
         if substs.needs_subst() {
-            // We can't resolve on types that require monomorphization, so we don't handle them if
-            // we need to perfom substitution.
+            // We can't resolve on types that require further substitution, so we only handle the
+            // case where the unresolved obligations still let us prove the call is a noop: a
+            // `.clone()` on a `&T` receiver is always a reference copy, regardless of whether `T`
+            // itself implements `Clone`, as long as no bound in scope requires `T: Clone` (which
+            // would make the compiler prefer an autoderef'd call into `T`'s own `Clone` impl
+            // instead).
+            if cx.tcx.is_diagnostic_item(sym::Clone, trait_id) {
+                self.check_generic_clone(cx, expr, &call.ident.name, &elements[0]);
+            }
             return;
         }
         let param_env = cx.tcx.param_env(trait_id);
@@ -70,48 +85,117 @@ impl<'tcx> LateLintPass<'tcx> for NoopMethodCall {
             Ok(Some(i)) => i,
             _ => return,
         };
-        // (Re)check that it implements the noop diagnostic.
-        for (s, peel_ref) in [(sym::noop_method_clone, false)].iter() {
+        // (Re)check that it implements the noop diagnostic. The receiver's type is compared as
+        // is, before any autoref, with `expr_ty_adjusted` below: for `Clone`, `Deref` and
+        // `Borrow` a match means the call hands back the reference it was given, and for
+        // `ToOwned` on a `Clone` type that is not a reference it means the call returns a clone
+        // of the receiver's own type.
+        for s in [
+            sym::noop_method_clone,
+            sym::noop_method_deref,
+            sym::noop_method_borrow,
+            sym::noop_method_to_owned,
+        ]
+        .iter()
+        {
             if cx.tcx.is_diagnostic_item(*s, i.def_id()) {
                 let method = &call.ident.name;
                 let receiver = &elements[0];
                 let receiver_ty = cx.typeck_results().expr_ty(receiver);
-                let receiver_ty = match receiver_ty.kind() {
-                    // Remove one borrow from the receiver if appropriate to positively verify that
-                    // the receiver `&self` type and the return type are the same, depending on the
-                    // involved trait being checked.
-                    ty::Ref(_, ty, _) if *peel_ref => ty,
-                    // When it comes to `Clone` we need to check the `receiver_ty` directly.
-                    // FIXME: we must come up with a better strategy for this.
-                    _ => receiver_ty,
-                };
                 let expr_ty = cx.typeck_results().expr_ty_adjusted(expr);
                 if receiver_ty != expr_ty {
                     // This lint will only trigger if the receiver type and resulting expression \
                     // type are the same, implying that the method call is unnecessary.
                     return;
                 }
-                let expr_span = expr.span;
-                let note = format!(
-                    "the type `{:?}` which `{}` is being called on is the same as \
-                     the type returned from `{}`, so the method call does not do \
-                     anything and can be removed",
-                    receiver_ty, method, method,
-                );
-
-                let span = expr_span.with_lo(receiver.span.hi());
-                cx.struct_span_lint(NOOP_METHOD_CALL, span, |lint| {
-                    let method = &call.ident.name;
-                    let message = format!(
-                        "call to `.{}()` on a reference in this situation does nothing",
-                        &method,
-                    );
-                    lint.build(&message)
-                        .span_label(span, "unnecessary method call")
-                        .note(&note)
-                        .emit()
-                });
+                emit_noop_method_call_lint(cx, expr, method, receiver, receiver_ty);
             }
         }
     }
 }
+
+impl NoopMethodCall {
+    /// Handles `.clone()` calls whose substs still need substitution (e.g. inside a generic
+    /// function body), where `Instance::resolve` cannot be used directly. If the receiver is a
+    /// reference `&T` to a type parameter `T` that isn't bound by `Clone` in the enclosing item's
+    /// `ParamEnv`, the only `Clone` impl that can possibly apply is the blanket
+    /// `impl<T> Clone for &T`, so the call is provably a noop reference copy without needing to
+    /// monomorphize anything.
+    fn check_generic_clone<'tcx>(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        expr: &'tcx Expr<'_>,
+        method: &rustc_span::Symbol,
+        receiver: &'tcx Expr<'_>,
+    ) {
+        let receiver_ty = cx.typeck_results().expr_ty(receiver);
+        let pointee_ty = match receiver_ty.kind() {
+            ty::Ref(_, pointee_ty, _) => pointee_ty,
+            _ => return,
+        };
+        match pointee_ty.kind() {
+            ty::Param(_) => {}
+            _ => return,
+        }
+
+        let param_env = cx.param_env();
+        let clone_trait = cx.tcx.lang_items().clone_trait();
+        // `T: Clone` need not appear as a literal bound: it can also be implied transitively
+        // through a supertrait (e.g. `trait MyClone: Clone {}`, `T: MyClone`). Elaborate the
+        // caller's bounds the same way method probing does before concluding `T` has no bound
+        // that could make `clone` resolve to `T`'s own impl instead of the blanket `&T` one.
+        let is_bound_by_clone = clone_trait.map_or(false, |clone_trait| {
+            elaborate_predicates(cx.tcx, param_env.caller_bounds().iter()).any(|obligation| {
+                matches!(
+                    obligation.predicate.kind().skip_binder(),
+                    ty::PredicateKind::Trait(t)
+                        if t.def_id() == clone_trait && t.self_ty() == *pointee_ty
+                )
+            })
+        });
+        if is_bound_by_clone {
+            // `T: Clone` is in scope (possibly via a supertrait), so the call resolves to `T`'s
+            // own `Clone` impl, which returns a `T` rather than a copy of the reference.
+            return;
+        }
+
+        let expr_ty = cx.typeck_results().expr_ty_adjusted(expr);
+        if receiver_ty != expr_ty {
+            return;
+        }
+        emit_noop_method_call_lint(cx, expr, method, receiver, receiver_ty);
+    }
+}
+
+fn emit_noop_method_call_lint<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    method: &rustc_span::Symbol,
+    receiver: &'tcx Expr<'_>,
+    receiver_ty: ty::Ty<'tcx>,
+) {
+    let note = format!(
+        "the type `{:?}` which `{}` is being called on is the same as \
+         the type returned from `{}`, so the method call does not do \
+         anything and can be removed",
+        receiver_ty, method, method,
+    );
+    let span = expr.span.with_lo(receiver.span.hi());
+    cx.struct_span_lint(NOOP_METHOD_CALL, span, |lint| {
+        let message = if receiver_ty.is_ref() {
+            format!("call to `.{}()` on a reference in this situation does nothing", method)
+        } else {
+            format!("call to `.{}()` in this situation does nothing", method)
+        };
+        lint.build(&message)
+            .span_label(span, "unnecessary method call")
+            .note(&note)
+            .span_suggestion(
+                span,
+                "remove this method call",
+                String::new(),
+                Applicability::MachineApplicable,
+            )
+            .emit()
+    });
+}
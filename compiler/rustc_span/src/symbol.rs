@@ -0,0 +1,13 @@
+// Excerpt of the central symbol table relevant to `rustc_lint::noop_method_call`. The real
+// `symbols!` invocation declares several thousand entries; only the ones this pass depends on
+// are reproduced here.
+symbols! {
+    Symbols {
+        // ...
+        noop_method_borrow,
+        noop_method_clone,
+        noop_method_deref,
+        noop_method_to_owned,
+        // ...
+    }
+}
@@ -0,0 +1,12 @@
+#[stable(feature = "rust1", since = "1.0.0")]
+impl<T> ToOwned for T
+where
+    T: Clone,
+{
+    type Owned = T;
+
+    #[rustc_diagnostic_item = "noop_method_to_owned"]
+    fn to_owned(&self) -> T {
+        self.clone()
+    }
+}
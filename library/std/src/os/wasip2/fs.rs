@@ -0,0 +1,81 @@
+//! WASI-specific extensions to primitives in the `std::fs` module.
+
+use crate::fs::{self, Metadata};
+use crate::sys_common::AsInner;
+
+#[unstable(feature = "wasip2_ext", issue = "135854")]
+/// WASI-specific extensions to [`fs::Metadata`].
+pub trait MetadataExt {
+    /// Returns the device ID on which this file resides.
+    fn dev(&self) -> u64;
+    /// Returns the inode number of this file.
+    fn ino(&self) -> u64;
+    /// Returns the number of hard links to this file.
+    fn nlink(&self) -> u64;
+    /// Returns the size of the file, in bytes, this metadata is for.
+    fn size(&self) -> u64;
+    /// Returns the last access time of the file, in nanoseconds since the Unix epoch.
+    fn atim(&self) -> u64;
+    /// Returns the last modification time of the file, in nanoseconds since the Unix epoch.
+    fn mtim(&self) -> u64;
+    /// Returns the last status change time of the file, in nanoseconds since the Unix epoch.
+    fn ctim(&self) -> u64;
+}
+
+#[unstable(feature = "wasip2_ext", issue = "135854")]
+impl MetadataExt for Metadata {
+    fn dev(&self) -> u64 {
+        self.as_inner().as_inner().dev
+    }
+    fn ino(&self) -> u64 {
+        self.as_inner().as_inner().ino
+    }
+    fn nlink(&self) -> u64 {
+        self.as_inner().as_inner().nlink
+    }
+    fn size(&self) -> u64 {
+        self.as_inner().as_inner().size
+    }
+    fn atim(&self) -> u64 {
+        self.as_inner().as_inner().atim
+    }
+    fn mtim(&self) -> u64 {
+        self.as_inner().as_inner().mtim
+    }
+    fn ctim(&self) -> u64 {
+        self.as_inner().as_inner().ctim
+    }
+}
+
+/// WASI-specific extensions for [`fs::FileType`].
+///
+/// Adds support for querying the WASI-specific file kinds that don't have a
+/// portable `std::fs::FileType` equivalent, such as block/character devices
+/// and sockets.
+#[unstable(feature = "wasip2_ext", issue = "135854")]
+pub trait FileTypeExt {
+    /// Returns `true` if this file type is a block device.
+    fn is_block_device(&self) -> bool;
+    /// Returns `true` if this file type is a character device.
+    fn is_char_device(&self) -> bool;
+    /// Returns `true` if this file type is a socket.
+    fn is_socket(&self) -> bool;
+    /// Returns `true` if this file type is a symbolic link.
+    fn is_symlink(&self) -> bool;
+}
+
+#[unstable(feature = "wasip2_ext", issue = "135854")]
+impl FileTypeExt for fs::FileType {
+    fn is_block_device(&self) -> bool {
+        self.as_inner().is(crate::sys::fs::FileType::BlockDevice)
+    }
+    fn is_char_device(&self) -> bool {
+        self.as_inner().is(crate::sys::fs::FileType::CharacterDevice)
+    }
+    fn is_socket(&self) -> bool {
+        self.as_inner().is(crate::sys::fs::FileType::Socket)
+    }
+    fn is_symlink(&self) -> bool {
+        self.as_inner().is(crate::sys::fs::FileType::Symlink)
+    }
+}
@@ -1,10 +1,14 @@
 //! Platform-specific extensions to `std` for Preview 2 of the WebAssembly System Interface (WASI).
 //!
 //! This module is currently fairly bare-bones, but will be expanded in the future as more items are stabilized.
+//! The `fs` extension traits are gated behind the `wasip2_ext` unstable feature while the
+//! underlying preview 2 filesystem bindings are still in flux.
 
 #![forbid(unsafe_op_in_unsafe_fn)]
 #![stable(feature = "raw_ext", since = "1.1.0")]
 
+pub mod fs;
+
 /// A prelude for conveniently writing platform-specific code.
 ///
 /// Includes all extension traits, and some important type definitions.
@@ -13,4 +17,7 @@ pub mod prelude {
     #[doc(no_inline)]
     #[stable(feature = "rust1", since = "1.0.0")]
     pub use super::ffi::OsStrExt;
+    #[doc(no_inline)]
+    #[unstable(feature = "wasip2_ext", issue = "135854")]
+    pub use super::fs::{FileTypeExt, MetadataExt};
 }
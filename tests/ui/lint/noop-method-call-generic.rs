@@ -0,0 +1,28 @@
+// check-pass
+
+#![allow(unused)]
+
+fn foo<T>(x: &T) {
+    x.clone();
+    //~^ WARN call to `.clone()` on a reference in this situation does nothing
+}
+
+fn bar<T: Clone>(x: &T) {
+    // `T: Clone` is in scope here, so this resolves to `T`'s own `Clone` impl, which returns a
+    // `T` rather than a copy of the reference: the lint must not fire here.
+    x.clone();
+}
+
+trait MyClone: Clone {}
+
+fn baz<T: MyClone>(x: &T) {
+    // `T: Clone` is only in scope transitively, through the `MyClone` supertrait, but that's
+    // enough to make this resolve to `T`'s own `Clone` impl as in `bar` above: the lint must not
+    // fire here.
+    x.clone();
+}
+
+fn main() {
+    foo(&String::new());
+    bar(&String::new());
+}
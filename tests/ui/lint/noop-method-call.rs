@@ -0,0 +1,38 @@
+// run-rustfix
+
+#![allow(unused)]
+use std::borrow::Borrow;
+use std::ops::Deref;
+
+struct Foo;
+struct Bar;
+
+impl Deref for Bar {
+    type Target = Foo;
+    fn deref(&self) -> &Foo {
+        &Foo
+    }
+}
+
+#[derive(Clone)]
+struct Baz;
+
+fn main() {
+    let foo = Foo;
+    let bar = Bar;
+    let baz = Baz;
+
+    (&foo).clone();
+    //~^ WARN call to `.clone()` on a reference in this situation does nothing
+    (&foo).deref();
+    //~^ WARN call to `.deref()` on a reference in this situation does nothing
+    (&foo).borrow();
+    //~^ WARN call to `.borrow()` on a reference in this situation does nothing
+    (&foo).to_owned();
+    //~^ WARN call to `.to_owned()` on a reference in this situation does nothing
+    baz.to_owned();
+    //~^ WARN call to `.to_owned()` in this situation does nothing
+
+    // This is a real deref (not a noop) and should not lint:
+    bar.deref();
+}
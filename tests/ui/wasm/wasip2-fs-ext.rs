@@ -0,0 +1,25 @@
+// only-wasm32-wasip2
+// check-pass
+
+#![feature(wasip2_ext)]
+
+use std::fs::File;
+use std::os::wasip2::fs::{FileTypeExt, MetadataExt};
+
+fn main() {
+    let file = File::open("foo.txt").unwrap();
+    let metadata = file.metadata().unwrap();
+    let _ = metadata.dev();
+    let _ = metadata.ino();
+    let _ = metadata.nlink();
+    let _ = metadata.size();
+    let _ = metadata.atim();
+    let _ = metadata.mtim();
+    let _ = metadata.ctim();
+
+    let file_type = metadata.file_type();
+    let _ = file_type.is_block_device();
+    let _ = file_type.is_char_device();
+    let _ = file_type.is_socket();
+    let _ = file_type.is_symlink();
+}